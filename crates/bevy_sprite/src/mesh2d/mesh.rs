@@ -1,23 +1,29 @@
 use bevy_app::Plugin;
-use bevy_asset::{Assets, Handle, HandleUntyped};
+use bevy_asset::{Assets, Handle, HandleId, HandleUntyped};
 use bevy_ecs::{
     prelude::*,
     system::{lifetimeless::*, SystemParamItem},
 };
-use bevy_math::{Mat4, Size};
+use bevy_math::{Mat4, Size, Vec4};
 use bevy_reflect::TypeUuid;
 use bevy_render::{
     mesh::{GpuBufferInfo, Mesh},
     render_asset::RenderAssets,
     render_component::{ComponentUniforms, DynamicUniformIndex, UniformComponentPlugin},
+    render_graph::{Node, NodeRunError, RenderGraph, RenderGraphContext},
     render_phase::{EntityRenderCommand, RenderCommandResult, TrackedRenderPass},
     render_resource::{std140::AsStd140, *},
-    renderer::{RenderDevice, RenderQueue},
+    renderer::{RenderContext, RenderDevice, RenderQueue},
     texture::{BevyDefault, GpuImage, Image, TextureFormatPixelInfo},
-    view::{ComputedVisibility, ExtractedView, ViewUniform, ViewUniformOffset, ViewUniforms},
+    view::{
+        ComputedVisibility, ExtractedView, VisibleEntities, ViewUniform, ViewUniformOffset,
+        ViewUniforms,
+    },
     RenderApp, RenderStage,
 };
 use bevy_transform::components::GlobalTransform;
+use bevy_utils::{tracing::warn, HashMap};
+use bytemuck::{Pod, Zeroable};
 
 /// Component for rendering with meshes in the 2d pipeline, usually with a [2d material](crate::Material2d) such as [`ColorMaterial`](crate::ColorMaterial).
 ///
@@ -41,6 +47,10 @@ pub const MESH2D_STRUCT_HANDLE: HandleUntyped =
 pub const MESH2D_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 2971387252468633715);
 
+/// Name of the [`Mesh2dDeformNode`] in the `core_2d` render subgraph, ordered before
+/// `MAIN_PASS` so `Transparent2d` draws deformed vertices rather than stale ones.
+pub const MESH2D_DEFORM_NODE: &str = "mesh2d_deform";
+
 impl Plugin for Mesh2dRenderPlugin {
     fn build(&self, app: &mut bevy_app::App) {
         let mut shaders = app.world.get_resource_mut::<Assets<Shader>>().unwrap();
@@ -61,12 +71,49 @@ impl Plugin for Mesh2dRenderPlugin {
 
         app.add_plugin(UniformComponentPlugin::<Mesh2dUniform>::default());
 
-        app.sub_app_mut(RenderApp)
+        let render_app = app.sub_app_mut(RenderApp);
+        render_app
             .init_resource::<Mesh2dPipeline>()
             .init_resource::<SpecializedPipelines<Mesh2dPipeline>>()
+            .init_resource::<Mesh2dInstances>()
+            .init_resource::<Mesh2dComputePipeline>()
+            .init_resource::<Mesh2dDeformOutputs>()
+            .init_resource::<Mesh2dImageFormats>()
             .add_system_to_stage(RenderStage::Extract, extract_mesh2d)
+            .add_system_to_stage(RenderStage::Extract, extract_mesh2d_deform)
+            .add_system_to_stage(RenderStage::Extract, extract_mesh2d_image_formats)
+            .add_system_to_stage(RenderStage::Queue, queue_mesh2d_instances)
+            .add_system_to_stage(RenderStage::Queue, queue_mesh2d_deform_bind_groups)
             .add_system_to_stage(RenderStage::Queue, queue_mesh2d_bind_group)
             .add_system_to_stage(RenderStage::Queue, queue_mesh2d_view_bind_groups);
+
+        // Add the deform node to the 2d core subgraph and order it before `MAIN_PASS`
+        // (which runs `Transparent2d`, among other phases) so deformed vertices are
+        // ready before the pass reads them.
+        let mut render_graph = render_app.world.get_resource_mut::<RenderGraph>().unwrap();
+        let draw_2d_graph = render_graph
+            .get_sub_graph_mut(bevy_core_pipeline::core_2d::graph::NAME)
+            .unwrap();
+        draw_2d_graph.add_node(MESH2D_DEFORM_NODE, Mesh2dDeformNode);
+        draw_2d_graph
+            .add_node_edge(
+                MESH2D_DEFORM_NODE,
+                bevy_core_pipeline::core_2d::graph::node::MAIN_PASS,
+            )
+            .unwrap();
+
+        #[cfg(feature = "gpu_profiling")]
+        {
+            let timings = gpu_profiling::Mesh2dGpuTimings::default();
+            app.insert_resource(timings.clone());
+            render_app
+                .insert_resource(timings)
+                .init_resource::<gpu_profiling::Mesh2dGpuProfiler>()
+                .add_system_to_stage(
+                    RenderStage::Cleanup,
+                    gpu_profiling::resolve_mesh2d_gpu_profiler,
+                );
+        }
     }
 }
 
@@ -113,12 +160,446 @@ pub fn extract_mesh2d(
     commands.insert_or_spawn_batch(values);
 }
 
+/// Per-instance GPU data for the instanced Mesh2d draw path, written one-per-entity
+/// into a mesh group's instance [`Buffer`] and read by `mesh2d.wgsl` in place of the
+/// per-entity [`Mesh2dUniform`] when `INSTANCED` is set. Carries `transform` (as `model`)
+/// and `flags` from [`Mesh2dUniform`] since the group's shared `Mesh2dBindGroup` only reflects the
+/// representative entity's own uniform and can't stand in for every instance's values
+/// once a group is instanced. `inverse_transpose_model` is deliberately left out: most
+/// backends (WebGL2 included, which this module already special-cases) only guarantee
+/// 16 vertex attribute slots, and `model` alone already needs 4 of the 8 remaining
+/// after per-vertex attributes — the shader reconstructs the normal matrix from `model`
+/// instead (see `mat3_inverse_transpose` in mesh2d.wgsl).
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct Mesh2dInstanceData {
+    pub model: [Vec4; 4],
+    pub flags: u32,
+    _padding: [u32; 3],
+}
+
+struct Mesh2dInstanceBuffer {
+    buffer: Buffer,
+    length: u32,
+}
+
+/// Instance grouping for a single view. Kept separate per view (rather than one global
+/// grouping) because an entity's visibility is itself per-view: two entities sharing a
+/// mesh can be visible to different cameras, and a representative chosen without regard
+/// to the view would end up drawing a group member that isn't actually visible there
+/// while skipping one that is, silently dropping it from that view's output.
+#[derive(Default)]
+struct Mesh2dInstancesForView {
+    buffers: HashMap<HandleId, Mesh2dInstanceBuffer>,
+    /// Maps every grouped entity to the single entity that owns this view's draw call
+    /// for its mesh group; all other entities in the group skip drawing in
+    /// [`DrawMesh2d`] for this view.
+    representative: HashMap<Entity, Entity>,
+}
+
+/// Groups extracted Mesh2d entities that share a mesh into a single per-mesh instance
+/// buffer so they can be drawn with one `draw`/`draw_indexed` call instead of one per
+/// entity. Grouping is scoped per view (see [`Mesh2dInstancesForView`]) and rebuilt
+/// every frame by [`queue_mesh2d_instances`].
+#[derive(Default)]
+pub struct Mesh2dInstances {
+    views: HashMap<Entity, Mesh2dInstancesForView>,
+}
+
+impl Mesh2dInstances {
+    fn instance_buffer(&self, view: Entity, mesh_id: HandleId) -> Option<&Buffer> {
+        self.views
+            .get(&view)?
+            .buffers
+            .get(&mesh_id)
+            .map(|instances| &instances.buffer)
+    }
+
+    fn instance_count(&self, view: Entity, mesh_id: HandleId) -> u32 {
+        self.views
+            .get(&view)
+            .and_then(|for_view| for_view.buffers.get(&mesh_id))
+            .map_or(1, |instances| instances.length)
+    }
+
+    fn is_drawn_by(&self, view: Entity, entity: Entity) -> Option<Entity> {
+        self.views.get(&view)?.representative.get(&entity).copied()
+    }
+}
+
+/// Groups each view's visible entities by their mesh's weak [`HandleId`] and uploads a
+/// contiguous instance buffer for every group with more than one member; single-member
+/// groups are left alone so [`DrawMesh2d`] falls back to its non-instanced path and we
+/// avoid the churn of a one-instance buffer. Grouping is done independently per view
+/// (via each view's own [`VisibleEntities`]) so a representative is never chosen for a
+/// view it isn't actually visible in. [`Mesh2dDeform`] entities are excluded entirely:
+/// an instanced group shares one vertex buffer for its whole draw call, which would
+/// silently drop every non-representative member's own deformed vertices.
+pub fn queue_mesh2d_instances(
+    mut mesh2d_instances: ResMut<Mesh2dInstances>,
+    render_device: Res<RenderDevice>,
+    views: Query<(Entity, &VisibleEntities), With<ExtractedView>>,
+    mesh2d_query: Query<(&Mesh2dHandle, &Mesh2dUniform), Without<Mesh2dDeform>>,
+) {
+    mesh2d_instances.views.clear();
+
+    for (view_entity, visible_entities) in views.iter() {
+        let mut groups: HashMap<HandleId, Vec<(Entity, Mesh2dInstanceData)>> = HashMap::default();
+        for &entity in &visible_entities.entities {
+            let Ok((mesh_handle, uniform)) = mesh2d_query.get(entity) else {
+                continue;
+            };
+            let instance_data = Mesh2dInstanceData {
+                model: [
+                    uniform.transform.x_axis,
+                    uniform.transform.y_axis,
+                    uniform.transform.z_axis,
+                    uniform.transform.w_axis,
+                ],
+                flags: uniform.flags,
+                _padding: [0; 3],
+            };
+            groups
+                .entry(mesh_handle.0.id())
+                .or_default()
+                .push((entity, instance_data));
+        }
+
+        let mut for_view = Mesh2dInstancesForView::default();
+        for (mesh_id, group) in groups {
+            if group.len() < 2 {
+                continue;
+            }
+            let representative = group[0].0;
+            let data: Vec<Mesh2dInstanceData> = group.iter().map(|(_, data)| *data).collect();
+            let buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
+                label: Some("mesh2d_instance_buffer"),
+                contents: bytemuck::cast_slice(&data),
+                usage: BufferUsages::VERTEX,
+            });
+            for (entity, _) in &group {
+                for_view.representative.insert(*entity, representative);
+            }
+            for_view.buffers.insert(
+                mesh_id,
+                Mesh2dInstanceBuffer {
+                    buffer,
+                    length: data.len() as u32,
+                },
+            );
+        }
+        mesh2d_instances.views.insert(view_entity, for_view);
+    }
+}
+
+/// Marks a Mesh2d entity for GPU-side vertex deformation (2D skeletal skinning,
+/// wave/cloth simulation, morph targets, ...) before it's drawn. [`DrawMesh2d`] binds
+/// the computed scratch buffer in place of the mesh's static vertex buffer when present.
+///
+/// Attached in the main world, so `bones` is plain CPU data rather than a GPU [`Buffer`]
+/// — the render world's `RenderDevice` that a bone buffer would need doesn't exist yet
+/// when a user spawns this. [`extract_mesh2d_deform`] carries it across unchanged, and
+/// [`queue_mesh2d_deform_bind_groups`] uploads it to a real storage buffer once it has
+/// device access.
+#[derive(Component, Clone)]
+pub struct Mesh2dDeform {
+    /// Per-bone 2D transform (or other deformation driver), laid out to match
+    /// `mesh2d_deform.wgsl`'s `Bone` struct.
+    pub bones: Vec<Mat4>,
+    pub vertex_count: u32,
+}
+
+/// Copies every [`Mesh2dDeform`] into the render world unchanged. Unlike
+/// [`extract_mesh2d`], there's no uniform/matrix computation to do here — `bones` is
+/// already render-ready CPU data once [`queue_mesh2d_deform_bind_groups`] uploads it.
+pub fn extract_mesh2d_deform(
+    mut commands: Commands,
+    mut previous_len: Local<usize>,
+    query: Query<(Entity, &Mesh2dDeform)>,
+) {
+    let mut values = Vec::with_capacity(*previous_len);
+    for (entity, deform) in query.iter() {
+        values.push((entity, deform.clone()));
+    }
+    *previous_len = values.len();
+    commands.insert_or_spawn_batch(values);
+}
+
+const MESH2D_DEFORM_WORKGROUP_SIZE: u32 = 64;
+
+/// Compute pipeline that deforms Mesh2d vertices on the GPU ahead of the render phase.
+/// Holds the bind group layout shared by every [`Mesh2dDeform`] entity (input vertices,
+/// bone/weight buffer, output vertices) and the compiled pipeline itself.
+pub struct Mesh2dComputePipeline {
+    pub bind_group_layout: BindGroupLayout,
+    pub pipeline: ComputePipeline,
+}
+
+impl FromWorld for Mesh2dComputePipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.get_resource::<RenderDevice>().unwrap();
+        let bind_group_layout =
+            render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("mesh2d_deform_layout"),
+                entries: &[
+                    // Input vertices
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Buffer {
+                            ty: BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // Bones/weights
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Buffer {
+                            ty: BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // Output vertices
+                    BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Buffer {
+                            ty: BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let shader_module = render_device.create_shader_module(&ShaderModuleDescriptor {
+            label: Some("mesh2d_deform_shader"),
+            source: ShaderSource::Wgsl(include_str!("mesh2d_deform.wgsl").into()),
+        });
+        let pipeline_layout = render_device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("mesh2d_deform_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = render_device.create_compute_pipeline(&ComputePipelineDescriptor {
+            label: Some("mesh2d_deform_pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader_module,
+            entry_point: "deform",
+        });
+
+        Mesh2dComputePipeline {
+            bind_group_layout,
+            pipeline,
+        }
+    }
+}
+
+struct Mesh2dDeformOutput {
+    buffer: Buffer,
+    /// Storage-usage copy of the mesh's vertex buffer, refreshed every frame by a GPU
+    /// copy in [`queue_mesh2d_deform_bind_groups`]; the compute shader reads from this
+    /// instead of `gpu_mesh.vertex_buffer` directly, since that buffer is uploaded by
+    /// `bevy_render` with only `BufferUsages::VERTEX` and can't be bound as storage.
+    input_buffer: Buffer,
+    /// Kept alive alongside `bind_group`, which references it; not read directly
+    /// elsewhere since `bind_group` already binds it for the dispatch.
+    bones_buffer: Buffer,
+    bind_group: BindGroup,
+    workgroups: u32,
+}
+
+/// Per-entity output of the compute deform pass, rebuilt every frame by
+/// [`queue_mesh2d_deform_bind_groups`]. [`DrawMesh2d`] consults this to pick the
+/// deformed buffer over the mesh's static vertex buffer.
+#[derive(Default)]
+pub struct Mesh2dDeformOutputs(HashMap<Entity, Mesh2dDeformOutput>);
+
+impl Mesh2dDeformOutputs {
+    fn output_buffer(&self, entity: Entity) -> Option<&Buffer> {
+        self.0.get(&entity).map(|output| &output.buffer)
+    }
+}
+
+/// Allocates each [`Mesh2dDeform`] entity a storage-usage input buffer and scratch
+/// output buffer sized to its mesh's vertex buffer, copies the mesh's vertex data into
+/// the input buffer, and builds the bind group the compute pass dispatches against.
+///
+/// `gpu_mesh.vertex_buffer` itself is created by `bevy_render` with only
+/// `BufferUsages::VERTEX`, so it can't be bound as a storage buffer directly. Rather
+/// than binding it, this copies it into `input_buffer`, a buffer this module creates
+/// with `BufferUsages::STORAGE`, using a one-off command encoder the same way
+/// [`gpu_profiling::resolve_mesh2d_gpu_profiler`] submits its own encoder outside the
+/// render graph.
+pub fn queue_mesh2d_deform_bind_groups(
+    mut outputs: ResMut<Mesh2dDeformOutputs>,
+    compute_pipeline: Res<Mesh2dComputePipeline>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    meshes: Res<RenderAssets<Mesh>>,
+    query: Query<(Entity, &Mesh2dHandle, &Mesh2dDeform)>,
+) {
+    outputs.0.clear();
+    for (entity, mesh_handle, deform) in query.iter() {
+        let gpu_mesh = match meshes.get(&mesh_handle.0) {
+            Some(gpu_mesh) => gpu_mesh,
+            None => continue,
+        };
+        let size = gpu_mesh.vertex_buffer.size();
+        let input_buffer = render_device.create_buffer(&BufferDescriptor {
+            label: Some("mesh2d_deform_input_buffer"),
+            size,
+            usage: BufferUsages::STORAGE | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let output_buffer = render_device.create_buffer(&BufferDescriptor {
+            label: Some("mesh2d_deform_output_buffer"),
+            size,
+            usage: BufferUsages::STORAGE | BufferUsages::VERTEX,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = render_device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("mesh2d_deform_input_copy"),
+        });
+        encoder.copy_buffer_to_buffer(&gpu_mesh.vertex_buffer, 0, &input_buffer, 0, size);
+        render_queue.submit(std::iter::once(encoder.finish()));
+
+        // `deform.bones` is CPU data extracted straight from the main world; upload it
+        // fresh each frame the same way `queue_mesh2d_instances` uploads instance data,
+        // since `Mesh2dDeform` can't carry a render-world `Buffer` across the extract
+        // boundary.
+        let bone_floats: Vec<f32> = deform
+            .bones
+            .iter()
+            .flat_map(|bone| bone.to_cols_array())
+            .collect();
+        let bones_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some("mesh2d_deform_bones_buffer"),
+            contents: bytemuck::cast_slice(&bone_floats),
+            usage: BufferUsages::STORAGE,
+        });
+
+        let bind_group = render_device.create_bind_group(&BindGroupDescriptor {
+            label: Some("mesh2d_deform_bind_group"),
+            layout: &compute_pipeline.bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: input_buffer.as_entire_binding(),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: bones_buffer.as_entire_binding(),
+                },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: output_buffer.as_entire_binding(),
+                },
+            ],
+        });
+        let workgroups =
+            (deform.vertex_count + MESH2D_DEFORM_WORKGROUP_SIZE - 1) / MESH2D_DEFORM_WORKGROUP_SIZE;
+
+        outputs.0.insert(
+            entity,
+            Mesh2dDeformOutput {
+                buffer: output_buffer,
+                input_buffer,
+                bones_buffer,
+                bind_group,
+                workgroups,
+            },
+        );
+    }
+}
+
+/// Render graph node that dispatches the compute deform pass for every
+/// [`Mesh2dDeform`] entity ahead of the Transparent2d pass.
+pub struct Mesh2dDeformNode;
+
+impl Node for Mesh2dDeformNode {
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let compute_pipeline = world.get_resource::<Mesh2dComputePipeline>().unwrap();
+        let outputs = world.get_resource::<Mesh2dDeformOutputs>().unwrap();
+        if outputs.0.is_empty() {
+            return Ok(());
+        }
+
+        let mut pass = render_context
+            .command_encoder
+            .begin_compute_pass(&ComputePassDescriptor {
+                label: Some("mesh2d_deform_pass"),
+            });
+        pass.set_pipeline(&compute_pipeline.pipeline);
+        for output in outputs.0.values() {
+            pass.set_bind_group(0, &output.bind_group, &[]);
+            pass.dispatch(output.workgroups, 1, 1);
+        }
+        Ok(())
+    }
+}
+
+bitflags::bitflags! {
+    /// Block-compressed texture feature support detected on the render adapter, so
+    /// uploaded [`Image`]s can stay compressed (BC1-BC7, ASTC, ETC2) instead of being
+    /// expanded to RGBA before upload.
+    pub struct CompressedImageFormats: u32 {
+        const NONE = 0;
+        const BC   = (1 << 0);
+        const ASTC = (1 << 1);
+        const ETC2 = (1 << 2);
+    }
+}
+
+/// Texture format of every loaded [`Image`] asset, extracted from the main world each
+/// frame so [`Mesh2dPipeline::get_image_texture`] can tell whether a given image's
+/// format is one this adapter can actually sample — a [`GpuImage`] alone doesn't carry
+/// its format, only the main-world [`Image`] it was uploaded from does.
+#[derive(Default)]
+pub struct Mesh2dImageFormats(HashMap<Handle<Image>, TextureFormat>);
+
+/// Mirrors every loaded [`Image`]'s format into [`Mesh2dImageFormats`] so the render
+/// world can reject formats the adapter doesn't support without needing a format field
+/// on [`GpuImage`] itself.
+pub fn extract_mesh2d_image_formats(mut commands: Commands, images: Res<Assets<Image>>) {
+    let formats = images
+        .iter()
+        .map(|(id, image)| (Handle::weak(id), image.texture_descriptor.format))
+        .collect();
+    commands.insert_resource(Mesh2dImageFormats(formats));
+}
+
+/// Computes a valid `bytes_per_row` for `format` at `width` pixels. Uncompressed
+/// formats measure pitch in pixels, but block-compressed formats (BC1-BC7, ASTC, ETC2)
+/// measure it in whole blocks, so the naive `width * pixel_size` math undercounts the
+/// row for any width that isn't a multiple of the block dimensions.
+fn bytes_per_row(format: TextureFormat, width: u32) -> u32 {
+    let info = format.describe();
+    let blocks_wide = (width + info.block_dimensions.0 as u32 - 1) / info.block_dimensions.0 as u32;
+    blocks_wide * info.block_size as u32
+}
+
 #[derive(Clone)]
 pub struct Mesh2dPipeline {
     pub view_layout: BindGroupLayout,
     pub mesh_layout: BindGroupLayout,
     // This dummy white texture is to be used in place of optional textures
     pub dummy_white_gpu_image: GpuImage,
+    /// Compressed texture formats this pipeline's adapter can sample directly,
+    /// detected once at startup from the adapter's [`Features`].
+    pub supported_compressed_formats: CompressedImageFormats,
 }
 
 impl FromWorld for Mesh2dPipeline {
@@ -154,6 +635,21 @@ impl FromWorld for Mesh2dPipeline {
             }],
             label: Some("mesh2d_layout"),
         });
+        let supported_compressed_formats = {
+            let features = render_device.features();
+            let mut formats = CompressedImageFormats::NONE;
+            if features.contains(Features::TEXTURE_COMPRESSION_BC) {
+                formats |= CompressedImageFormats::BC;
+            }
+            if features.contains(Features::TEXTURE_COMPRESSION_ASTC_LDR) {
+                formats |= CompressedImageFormats::ASTC;
+            }
+            if features.contains(Features::TEXTURE_COMPRESSION_ETC2) {
+                formats |= CompressedImageFormats::ETC2;
+            }
+            formats
+        };
+
         // A 1x1x1 'all 1.0' texture to use as a dummy texture to use in place of optional StandardMaterial textures
         let dummy_white_gpu_image = {
             let image = Image::new_fill(
@@ -165,7 +661,6 @@ impl FromWorld for Mesh2dPipeline {
             let texture = render_device.create_texture(&image.texture_descriptor);
             let sampler = render_device.create_sampler(&image.sampler_descriptor);
 
-            let format_size = image.texture_descriptor.format.pixel_size();
             let render_queue = world.get_resource_mut::<RenderQueue>().unwrap();
             render_queue.write_texture(
                 ImageCopyTexture {
@@ -178,9 +673,10 @@ impl FromWorld for Mesh2dPipeline {
                 ImageDataLayout {
                     offset: 0,
                     bytes_per_row: Some(
-                        std::num::NonZeroU32::new(
-                            image.texture_descriptor.size.width * format_size as u32,
-                        )
+                        std::num::NonZeroU32::new(bytes_per_row(
+                            image.texture_descriptor.format,
+                            image.texture_descriptor.size.width,
+                        ))
                         .unwrap(),
                     ),
                     rows_per_image: None,
@@ -203,25 +699,97 @@ impl FromWorld for Mesh2dPipeline {
             view_layout,
             mesh_layout,
             dummy_white_gpu_image,
+            supported_compressed_formats,
         }
     }
 }
 
+/// Whether `format` can be sampled directly by an adapter that supports
+/// `supported`: either it's uncompressed, or `supported` contains the
+/// `CompressedImageFormats` bit its block-compression family requires. Factored out of
+/// [`Mesh2dPipeline::supports_texture_format`] so it can be unit tested without needing
+/// a [`RenderDevice`] to build a [`Mesh2dPipeline`].
+fn supports_texture_format(supported: CompressedImageFormats, format: TextureFormat) -> bool {
+    match format {
+        f if f.describe().block_dimensions == (1, 1) => true,
+        TextureFormat::Bc1RgbaUnorm
+        | TextureFormat::Bc1RgbaUnormSrgb
+        | TextureFormat::Bc2RgbaUnorm
+        | TextureFormat::Bc2RgbaUnormSrgb
+        | TextureFormat::Bc3RgbaUnorm
+        | TextureFormat::Bc3RgbaUnormSrgb
+        | TextureFormat::Bc4RUnorm
+        | TextureFormat::Bc4RSnorm
+        | TextureFormat::Bc5RgUnorm
+        | TextureFormat::Bc5RgSnorm
+        | TextureFormat::Bc6hRgbUfloat
+        | TextureFormat::Bc6hRgbSfloat
+        | TextureFormat::Bc7RgbaUnorm
+        | TextureFormat::Bc7RgbaUnormSrgb => supported.contains(CompressedImageFormats::BC),
+        TextureFormat::Etc2Rgb8Unorm
+        | TextureFormat::Etc2Rgb8UnormSrgb
+        | TextureFormat::Etc2Rgb8A1Unorm
+        | TextureFormat::Etc2Rgb8A1UnormSrgb
+        | TextureFormat::Etc2Rgba8Unorm
+        | TextureFormat::Etc2Rgba8UnormSrgb
+        | TextureFormat::EacR11Unorm
+        | TextureFormat::EacR11Snorm
+        | TextureFormat::EacRg11Unorm
+        | TextureFormat::EacRg11Snorm => supported.contains(CompressedImageFormats::ETC2),
+        // Remaining block-compressed formats in this version's `TextureFormat` are
+        // the various ASTC block sizes.
+        _ => supported.contains(CompressedImageFormats::ASTC),
+    }
+}
+
 impl Mesh2dPipeline {
+    /// Whether `format` can be sampled directly by this pipeline's adapter: either it's
+    /// uncompressed, or the adapter reported the `TEXTURE_COMPRESSION_*` feature it
+    /// requires.
+    pub fn supports_texture_format(&self, format: TextureFormat) -> bool {
+        supports_texture_format(self.supported_compressed_formats, format)
+    }
+
+    /// Returns the texture/sampler pair to bind for `handle_option`, or the dummy white
+    /// texture when there's no handle *or* when the image's format isn't one this
+    /// adapter can sample. `image_formats` is consulted for the latter check since a
+    /// [`GpuImage`] doesn't carry its own format; an image whose format is missing from
+    /// it (not yet extracted) is assumed supported rather than rejected.
+    ///
+    /// This only controls what gets *bound* at draw time. Whether an [`Image`] is kept
+    /// compressed on upload in the first place is decided where the [`GpuImage`] itself
+    /// is built — `bevy_render`'s `RenderAsset<Image>` impl, a different crate not
+    /// present in this one's sources — so this function can't make an unsupported
+    /// format cheaper to have uploaded, only safe to draw with: reject to the dummy
+    /// texture instead of binding something that would fail wgpu validation.
     pub fn get_image_texture<'a>(
         &'a self,
         gpu_images: &'a RenderAssets<Image>,
+        image_formats: &Mesh2dImageFormats,
         handle_option: &Option<Handle<Image>>,
     ) -> Option<(&'a TextureView, &'a Sampler)> {
         if let Some(handle) = handle_option {
             let gpu_image = gpu_images.get(handle)?;
-            Some((&gpu_image.texture_view, &gpu_image.sampler))
-        } else {
-            Some((
-                &self.dummy_white_gpu_image.texture_view,
-                &self.dummy_white_gpu_image.sampler,
-            ))
+            let supported = image_formats
+                .0
+                .get(handle)
+                .map_or(true, |format| self.supports_texture_format(*format));
+            if supported {
+                return Some((&gpu_image.texture_view, &gpu_image.sampler));
+            }
+            // The adapter can't sample this image's compressed format directly and this
+            // module has no decompression path, so fall back to the dummy texture
+            // (itself uploaded through the same `bytes_per_row` row-alignment math)
+            // rather than binding a texture that would fail wgpu validation.
+            warn!(
+                "image format unsupported by this adapter, falling back to a dummy texture for {:?}",
+                handle
+            );
         }
+        Some((
+            &self.dummy_white_gpu_image.texture_view,
+            &self.dummy_white_gpu_image.sampler,
+        ))
     }
 }
 
@@ -233,6 +801,8 @@ bitflags::bitflags! {
     pub struct Mesh2dPipelineKey: u32 {
         const NONE                        = 0;
         const VERTEX_TANGENTS             = (1 << 0);
+        const INSTANCED                   = (1 << 1);
+        const VERTEX_COLORS               = (1 << 2);
         const MSAA_RESERVED_BITS          = Mesh2dPipelineKey::MSAA_MASK_BITS << Mesh2dPipelineKey::MSAA_SHIFT_BITS;
         const PRIMITIVE_TOPOLOGY_RESERVED_BITS = Mesh2dPipelineKey::PRIMITIVE_TOPOLOGY_MASK_BITS << Mesh2dPipelineKey::PRIMITIVE_TOPOLOGY_SHIFT_BITS;
     }
@@ -272,86 +842,217 @@ impl Mesh2dPipelineKey {
             _ => PrimitiveTopology::default(),
         }
     }
+
+    /// Returns [`Mesh2dPipelineKey::VERTEX_COLORS`] if `mesh` carries a color attribute,
+    /// otherwise [`Mesh2dPipelineKey::NONE`]. OR this into a mesh's pipeline key so
+    /// `specialize` compiles the variant that reads the per-vertex color.
+    pub fn from_mesh_vertex_colors(mesh: &Mesh) -> Self {
+        if mesh.attribute(Mesh::ATTRIBUTE_COLOR).is_some() {
+            Mesh2dPipelineKey::VERTEX_COLORS
+        } else {
+            Mesh2dPipelineKey::NONE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_per_row_uncompressed_matches_pixel_width() {
+        // Rgba8Unorm is 4 bytes/pixel, 1x1 blocks, so bytes_per_row is just width * 4.
+        assert_eq!(bytes_per_row(TextureFormat::Rgba8Unorm, 1), 4);
+        assert_eq!(bytes_per_row(TextureFormat::Rgba8Unorm, 3), 12);
+    }
+
+    #[test]
+    fn bytes_per_row_compressed_rounds_up_to_whole_blocks() {
+        // Bc1RgbaUnorm has 4x4 pixel blocks at 8 bytes/block; a width that isn't a
+        // multiple of 4 must still round up to a whole block rather than truncating.
+        for (width, expected_blocks_wide) in [(1, 1), (4, 1), (5, 2), (8, 2), (9, 3)] {
+            assert_eq!(
+                bytes_per_row(TextureFormat::Bc1RgbaUnorm, width),
+                expected_blocks_wide * 8,
+                "width {}",
+                width
+            );
+        }
+    }
+
+    #[test]
+    fn supports_texture_format_uncompressed_always_supported() {
+        assert!(supports_texture_format(
+            CompressedImageFormats::NONE,
+            TextureFormat::Rgba8Unorm
+        ));
+    }
+
+    #[test]
+    fn supports_texture_format_gated_by_matching_feature() {
+        let cases = [
+            (TextureFormat::Bc7RgbaUnorm, CompressedImageFormats::BC),
+            (TextureFormat::Etc2Rgba8Unorm, CompressedImageFormats::ETC2),
+            // EAC formats are part of the ETC2 extension, not a separate feature.
+            (TextureFormat::EacR11Unorm, CompressedImageFormats::ETC2),
+            (
+                TextureFormat::Astc4x4RgbaUnorm,
+                CompressedImageFormats::ASTC,
+            ),
+        ];
+        for (format, required) in cases {
+            assert!(
+                supports_texture_format(required, format),
+                "{:?} should be supported when {:?} is available",
+                format,
+                required
+            );
+            assert!(
+                !supports_texture_format(CompressedImageFormats::NONE, format),
+                "{:?} should be unsupported with no compressed formats available",
+                format
+            );
+            let wrong_feature = CompressedImageFormats::all() - required;
+            assert!(
+                !supports_texture_format(wrong_feature, format),
+                "{:?} shouldn't be supported by unrelated compressed formats",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn from_mesh_vertex_colors_detects_color_attribute() {
+        let mut without_colors = Mesh::new(PrimitiveTopology::TriangleList);
+        without_colors.insert_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0, 0.0, 0.0]]);
+        assert_eq!(
+            Mesh2dPipelineKey::from_mesh_vertex_colors(&without_colors),
+            Mesh2dPipelineKey::NONE
+        );
+
+        let mut with_colors = Mesh::new(PrimitiveTopology::TriangleList);
+        with_colors.insert_attribute(Mesh::ATTRIBUTE_COLOR, vec![[1.0, 1.0, 1.0, 1.0]]);
+        assert_eq!(
+            Mesh2dPipelineKey::from_mesh_vertex_colors(&with_colors),
+            Mesh2dPipelineKey::VERTEX_COLORS
+        );
+    }
 }
 
 impl SpecializedPipeline for Mesh2dPipeline {
     type Key = Mesh2dPipelineKey;
 
     fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
-        let (vertex_array_stride, vertex_attributes) =
-            if key.contains(Mesh2dPipelineKey::VERTEX_TANGENTS) {
-                (
-                    48,
-                    vec![
-                        // Position (GOTCHA! Vertex_Position isn't first in the buffer due to how Mesh sorts attributes (alphabetically))
-                        VertexAttribute {
-                            format: VertexFormat::Float32x3,
-                            offset: 12,
-                            shader_location: 0,
-                        },
-                        // Normal
-                        VertexAttribute {
-                            format: VertexFormat::Float32x3,
-                            offset: 0,
-                            shader_location: 1,
-                        },
-                        // Uv (GOTCHA! uv is no longer third in the buffer due to how Mesh sorts attributes (alphabetically))
-                        VertexAttribute {
-                            format: VertexFormat::Float32x2,
-                            offset: 40,
-                            shader_location: 2,
-                        },
-                        // Tangent
-                        VertexAttribute {
-                            format: VertexFormat::Float32x4,
-                            offset: 24,
-                            shader_location: 3,
-                        },
-                    ],
-                )
-            } else {
-                (
-                    32,
-                    vec![
-                        // Position (GOTCHA! Vertex_Position isn't first in the buffer due to how Mesh sorts attributes (alphabetically))
-                        VertexAttribute {
-                            format: VertexFormat::Float32x3,
-                            offset: 12,
-                            shader_location: 0,
-                        },
-                        // Normal
-                        VertexAttribute {
-                            format: VertexFormat::Float32x3,
-                            offset: 0,
-                            shader_location: 1,
-                        },
-                        // Uv
-                        VertexAttribute {
-                            format: VertexFormat::Float32x2,
-                            offset: 24,
-                            shader_location: 2,
-                        },
-                    ],
-                )
-            };
+        // Attributes are laid out in the order Mesh sorts them: alphabetically by name.
+        // With colors that's Color, Normal, Position, Tangent (optional), Uv.
+        let has_tangents = key.contains(Mesh2dPipelineKey::VERTEX_TANGENTS);
+        let has_colors = key.contains(Mesh2dPipelineKey::VERTEX_COLORS);
+
+        let mut vertex_array_stride = 0;
+        let mut vertex_attributes = Vec::new();
+
+        if has_colors {
+            // Color (GOTCHA! Vertex_Color sorts before the other attributes alphabetically)
+            vertex_attributes.push(VertexAttribute {
+                format: VertexFormat::Float32x4,
+                offset: vertex_array_stride,
+                shader_location: 4,
+            });
+            vertex_array_stride += 16;
+        }
+        // Normal
+        vertex_attributes.push(VertexAttribute {
+            format: VertexFormat::Float32x3,
+            offset: vertex_array_stride,
+            shader_location: 1,
+        });
+        vertex_array_stride += 12;
+        // Position (GOTCHA! Vertex_Position isn't first in the buffer due to how Mesh sorts attributes (alphabetically))
+        vertex_attributes.push(VertexAttribute {
+            format: VertexFormat::Float32x3,
+            offset: vertex_array_stride,
+            shader_location: 0,
+        });
+        vertex_array_stride += 12;
+        if has_tangents {
+            // Tangent
+            vertex_attributes.push(VertexAttribute {
+                format: VertexFormat::Float32x4,
+                offset: vertex_array_stride,
+                shader_location: 3,
+            });
+            vertex_array_stride += 16;
+        }
+        // Uv (GOTCHA! uv is no longer third in the buffer due to how Mesh sorts attributes (alphabetically))
+        vertex_attributes.push(VertexAttribute {
+            format: VertexFormat::Float32x2,
+            offset: vertex_array_stride,
+            shader_location: 2,
+        });
+        vertex_array_stride += 8;
+
         let mut shader_defs = Vec::new();
-        if key.contains(Mesh2dPipelineKey::VERTEX_TANGENTS) {
+        if has_tangents {
             shader_defs.push(String::from("VERTEX_TANGENTS"));
         }
+        if has_colors {
+            shader_defs.push(String::from("VERTEX_COLORS"));
+        }
 
         #[cfg(feature = "webgl")]
         shader_defs.push(String::from("NO_ARRAY_TEXTURES_SUPPORT"));
 
+        let mut vertex_buffers = vec![VertexBufferLayout {
+            array_stride: vertex_array_stride,
+            step_mode: VertexStepMode::Vertex,
+            attributes: vertex_attributes,
+        }];
+        if key.contains(Mesh2dPipelineKey::INSTANCED) {
+            shader_defs.push(String::from("INSTANCED"));
+            // Mirrors Mesh2dInstanceData: model columns, then flags, read per-instance
+            // instead of from the mesh uniform. Locations start at 8 to leave 4-7 free
+            // for per-vertex attributes (e.g. VERTEX_COLORS) that can be combined with
+            // instancing on the same pipeline, and stop at 12 to stay well within the
+            // 16 vertex attribute slots most backends (including WebGL2) guarantee.
+            vertex_buffers.push(VertexBufferLayout {
+                array_stride: 80,
+                step_mode: VertexStepMode::Instance,
+                attributes: vec![
+                    VertexAttribute {
+                        format: VertexFormat::Float32x4,
+                        offset: 0,
+                        shader_location: 8,
+                    },
+                    VertexAttribute {
+                        format: VertexFormat::Float32x4,
+                        offset: 16,
+                        shader_location: 9,
+                    },
+                    VertexAttribute {
+                        format: VertexFormat::Float32x4,
+                        offset: 32,
+                        shader_location: 10,
+                    },
+                    VertexAttribute {
+                        format: VertexFormat::Float32x4,
+                        offset: 48,
+                        shader_location: 11,
+                    },
+                    VertexAttribute {
+                        format: VertexFormat::Uint32,
+                        offset: 64,
+                        shader_location: 12,
+                    },
+                ],
+            });
+        }
+
         RenderPipelineDescriptor {
             vertex: VertexState {
                 shader: MESH2D_SHADER_HANDLE.typed::<Shader>(),
                 entry_point: "vertex".into(),
                 shader_defs: shader_defs.clone(),
-                buffers: vec![VertexBufferLayout {
-                    array_stride: vertex_array_stride,
-                    step_mode: VertexStepMode::Vertex,
-                    attributes: vertex_attributes,
-                }],
+                buffers: vertex_buffers,
             },
             fragment: Some(FragmentState {
                 shader: MESH2D_SHADER_HANDLE.typed::<Shader>(),
@@ -440,14 +1141,28 @@ pub fn queue_mesh2d_view_bind_groups(
 
 pub struct SetMesh2dViewBindGroup<const I: usize>;
 impl<const I: usize> EntityRenderCommand for SetMesh2dViewBindGroup<I> {
+    #[cfg(not(feature = "gpu_profiling"))]
     type Param = SQuery<(Read<ViewUniformOffset>, Read<Mesh2dViewBindGroup>)>;
+    #[cfg(feature = "gpu_profiling")]
+    type Param = (
+        SRes<gpu_profiling::Mesh2dGpuProfiler>,
+        SQuery<(Read<ViewUniformOffset>, Read<Mesh2dViewBindGroup>)>,
+    );
     #[inline]
     fn render<'w>(
         view: Entity,
         _item: Entity,
-        view_query: SystemParamItem<'w, '_, Self::Param>,
+        #[cfg(not(feature = "gpu_profiling"))] view_query: SystemParamItem<'w, '_, Self::Param>,
+        #[cfg(feature = "gpu_profiling")] (profiler, view_query): SystemParamItem<
+            'w,
+            '_,
+            Self::Param,
+        >,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
+        #[cfg(feature = "gpu_profiling")]
+        profiler.write_timestamp(pass);
+
         let (view_uniform, mesh2d_view_bind_group) = view_query.get(view).unwrap();
         pass.set_bind_group(I, &mesh2d_view_bind_group.value, &[view_uniform.offset]);
 
@@ -457,17 +1172,36 @@ impl<const I: usize> EntityRenderCommand for SetMesh2dViewBindGroup<I> {
 
 pub struct SetMesh2dBindGroup<const I: usize>;
 impl<const I: usize> EntityRenderCommand for SetMesh2dBindGroup<I> {
+    #[cfg(not(feature = "gpu_profiling"))]
     type Param = (
         SRes<Mesh2dBindGroup>,
         SQuery<Read<DynamicUniformIndex<Mesh2dUniform>>>,
     );
+    #[cfg(feature = "gpu_profiling")]
+    type Param = (
+        SRes<Mesh2dBindGroup>,
+        SRes<gpu_profiling::Mesh2dGpuProfiler>,
+        SQuery<Read<DynamicUniformIndex<Mesh2dUniform>>>,
+    );
     #[inline]
     fn render<'w>(
         _view: Entity,
         item: Entity,
-        (mesh2d_bind_group, mesh2d_query): SystemParamItem<'w, '_, Self::Param>,
+        #[cfg(not(feature = "gpu_profiling"))] (mesh2d_bind_group, mesh2d_query): SystemParamItem<
+            'w,
+            '_,
+            Self::Param,
+        >,
+        #[cfg(feature = "gpu_profiling")] (mesh2d_bind_group, profiler, mesh2d_query): SystemParamItem<
+            'w,
+            '_,
+            Self::Param,
+        >,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
+        #[cfg(feature = "gpu_profiling")]
+        profiler.write_timestamp(pass);
+
         let mesh2d_index = mesh2d_query.get(item).unwrap();
         pass.set_bind_group(
             I,
@@ -480,33 +1214,317 @@ impl<const I: usize> EntityRenderCommand for SetMesh2dBindGroup<I> {
 
 pub struct DrawMesh2d;
 impl EntityRenderCommand for DrawMesh2d {
-    type Param = (SRes<RenderAssets<Mesh>>, SQuery<Read<Mesh2dHandle>>);
+    #[cfg(not(feature = "gpu_profiling"))]
+    type Param = (
+        SRes<RenderAssets<Mesh>>,
+        SRes<Mesh2dInstances>,
+        SRes<Mesh2dDeformOutputs>,
+        SQuery<Read<Mesh2dHandle>>,
+    );
+    #[cfg(feature = "gpu_profiling")]
+    type Param = (
+        SRes<RenderAssets<Mesh>>,
+        SRes<Mesh2dInstances>,
+        SRes<Mesh2dDeformOutputs>,
+        SRes<gpu_profiling::Mesh2dGpuProfiler>,
+        SQuery<Read<Mesh2dHandle>>,
+    );
     #[inline]
     fn render<'w>(
-        _view: Entity,
+        view: Entity,
         item: Entity,
-        (meshes, mesh2d_query): SystemParamItem<'w, '_, Self::Param>,
+        #[cfg(not(feature = "gpu_profiling"))] (meshes, mesh2d_instances, deform_outputs, mesh2d_query): SystemParamItem<
+            'w,
+            '_,
+            Self::Param,
+        >,
+        #[cfg(feature = "gpu_profiling")] (meshes, mesh2d_instances, deform_outputs, profiler, mesh2d_query): SystemParamItem<
+            'w,
+            '_,
+            Self::Param,
+        >,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
-        let mesh_handle = &mesh2d_query.get(item).unwrap().0;
-        if let Some(gpu_mesh) = meshes.into_inner().get(mesh_handle) {
-            pass.set_vertex_buffer(0, gpu_mesh.vertex_buffer.slice(..));
-            match &gpu_mesh.buffer_info {
-                GpuBufferInfo::Indexed {
-                    buffer,
-                    index_format,
-                    count,
-                } => {
-                    pass.set_index_buffer(buffer.slice(..), 0, *index_format);
-                    pass.draw_indexed(0..*count, 0, 0..1);
+        // This entity's instance is already covered by its group's single instanced
+        // draw call below, issued by the group's representative entity for this view.
+        // Grouping (and so which entity is representative) is scoped per view, so an
+        // entity only visible to a different camera can never be the reason this one
+        // is skipped here.
+        let skip_draw = mesh2d_instances
+            .is_drawn_by(view, item)
+            .map_or(false, |representative| representative != item);
+
+        #[cfg(feature = "gpu_profiling")]
+        profiler.write_timestamp(pass);
+
+        // The profiling timestamps above and below bracket this whole block (including early
+        // returns) rather than just the draw call, so every entity's phase item
+        // contributes exactly `SPANS_PER_ENTITY` writes and later entities' blocks
+        // don't shift when this one is skipped or its mesh isn't loaded yet.
+        let result = if skip_draw {
+            RenderCommandResult::Success
+        } else {
+            let mesh_handle = &mesh2d_query.get(item).unwrap().0;
+            if let Some(gpu_mesh) = meshes.into_inner().get(mesh_handle) {
+                if let Some(deformed_buffer) = deform_outputs.output_buffer(item) {
+                    pass.set_vertex_buffer(0, deformed_buffer.slice(..));
+                } else {
+                    pass.set_vertex_buffer(0, gpu_mesh.vertex_buffer.slice(..));
                 }
-                GpuBufferInfo::NonIndexed { vertex_count } => {
-                    pass.draw(0..*vertex_count, 0..1);
+                let instances = if let Some(instance_buffer) =
+                    mesh2d_instances.instance_buffer(view, mesh_handle.0.id())
+                {
+                    pass.set_vertex_buffer(1, instance_buffer.slice(..));
+                    0..mesh2d_instances.instance_count(view, mesh_handle.0.id())
+                } else {
+                    0..1
+                };
+                match &gpu_mesh.buffer_info {
+                    GpuBufferInfo::Indexed {
+                        buffer,
+                        index_format,
+                        count,
+                    } => {
+                        pass.set_index_buffer(buffer.slice(..), 0, *index_format);
+                        pass.draw_indexed(0..*count, 0, instances);
+                    }
+                    GpuBufferInfo::NonIndexed { vertex_count } => {
+                        pass.draw(0..*vertex_count, instances);
+                    }
                 }
+                RenderCommandResult::Success
+            } else {
+                RenderCommandResult::Failure
             }
-            RenderCommandResult::Success
+        };
+
+        #[cfg(feature = "gpu_profiling")]
+        profiler.write_timestamp(pass);
+
+        result
+    }
+}
+
+/// GPU timestamp profiling for the Mesh2d render path, gated behind the `gpu_profiling`
+/// feature so it costs nothing when disabled. Degrades to a no-op on adapters that
+/// don't report [`Features::TIMESTAMP_QUERY`].
+#[cfg(feature = "gpu_profiling")]
+mod gpu_profiling {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    /// Checkpoints written around the Mesh2d render commands for a single entity, in
+    /// execution order; the gap between consecutive checkpoints is the GPU time spent
+    /// on that stage for that entity.
+    const SPANS_PER_ENTITY: u32 = 4;
+    /// Upper bound on how many entities' worth of timestamps a single frame can record.
+    /// `SetMesh2dViewBindGroup`/`SetMesh2dBindGroup`/`DrawMesh2d` run once per visible
+    /// entity (not once per frame), so each entity needs its own 4-slot block rather
+    /// than sharing 4 fixed indices across every entity in the phase.
+    const MAX_PROFILED_ENTITIES: u32 = 256;
+    const QUERY_COUNT: u32 = MAX_PROFILED_ENTITIES * SPANS_PER_ENTITY;
+
+    /// Millisecond GPU timings for the most recently resolved frame, averaged across
+    /// every entity profiled that frame. Written by [`resolve_mesh2d_gpu_profiler`] in
+    /// the render world and cloned into the main world at plugin build time so it can
+    /// be read back without round-tripping through `Extract`.
+    #[derive(Clone, Default)]
+    pub struct Mesh2dGpuTimings(pub Arc<Mutex<Mesh2dGpuTimingsInner>>);
+
+    #[derive(Default)]
+    pub struct Mesh2dGpuTimingsInner {
+        pub view_bind_group_ms: f32,
+        pub mesh_bind_group_ms: f32,
+        pub draw_ms: f32,
+        pub entities_profiled: u32,
+    }
+
+    /// Timestamp query set backing the Mesh2d GPU profiler, created once in `from_world`
+    /// when the resource is initialized rather than on first use. `query_set`
+    /// stays `None` on adapters without [`Features::TIMESTAMP_QUERY`], in which case
+    /// `write_timestamp` and the resolve system are no-ops. `next_query` hands out a
+    /// fresh slot to every `write_timestamp` call this frame and is reset once those
+    /// slots are resolved.
+    pub struct Mesh2dGpuProfiler {
+        query_set: Option<QuerySet>,
+        resolve_buffer: Option<Buffer>,
+        period_ns: f32,
+        next_query: AtomicU32,
+    }
+
+    impl FromWorld for Mesh2dGpuProfiler {
+        fn from_world(world: &mut World) -> Self {
+            let render_device = world.get_resource::<RenderDevice>().unwrap();
+            if !render_device.features().contains(Features::TIMESTAMP_QUERY) {
+                return Mesh2dGpuProfiler {
+                    query_set: None,
+                    resolve_buffer: None,
+                    period_ns: 1.0,
+                    next_query: AtomicU32::new(0),
+                };
+            }
+
+            let query_set = render_device
+                .wgpu_device()
+                .create_query_set(&QuerySetDescriptor {
+                    label: Some("mesh2d_gpu_profiler_query_set"),
+                    ty: QueryType::Timestamp,
+                    count: QUERY_COUNT,
+                });
+            let resolve_buffer = render_device.create_buffer(&BufferDescriptor {
+                label: Some("mesh2d_gpu_profiler_resolve_buffer"),
+                size: QUERY_COUNT as u64 * std::mem::size_of::<u64>() as u64,
+                usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            });
+            let render_queue = world.get_resource::<RenderQueue>().unwrap();
+            Mesh2dGpuProfiler {
+                query_set: Some(query_set),
+                resolve_buffer: Some(resolve_buffer),
+                period_ns: render_queue.get_timestamp_period(),
+                next_query: AtomicU32::new(0),
+            }
+        }
+    }
+
+    /// Returns the query-set slot to write `index` into, or `None` once `total` slots
+    /// for this frame have already been handed out. Factored out of
+    /// [`Mesh2dGpuProfiler::write_timestamp`] so the allocation bound is unit testable
+    /// without a `TrackedRenderPass`.
+    fn next_profiler_slot(index: u32, total: u32) -> Option<u32> {
+        if index < total {
+            Some(index)
         } else {
-            RenderCommandResult::Failure
+            None
+        }
+    }
+
+    /// Returns how many entities' worth of timestamps `written` raw slot writes cover,
+    /// clamped to `total` slots and rounded down to a whole number of
+    /// `spans_per_entity`-sized blocks (a frame can be interrupted mid-block by hitting
+    /// `MAX_PROFILED_ENTITIES`, and a partial block has nothing valid to resolve).
+    /// Factored out of [`resolve_mesh2d_gpu_profiler`] so it's unit testable without a
+    /// `RenderDevice`.
+    fn resolved_entities_profiled(written: u32, total: u32, spans_per_entity: u32) -> u32 {
+        written.min(total) / spans_per_entity
+    }
+
+    impl Mesh2dGpuProfiler {
+        /// Writes to the next free timestamp slot for the *current* entity's block.
+        /// Each entity's four render-command-site calls (view bind group, mesh bind
+        /// group, draw start, draw end) land consecutively in their own block, since
+        /// `SetMesh2dViewBindGroup`/`SetMesh2dBindGroup`/`DrawMesh2d` always run in
+        /// that fixed order for a given phase item. No-ops once `MAX_PROFILED_ENTITIES`
+        /// has been exceeded for this frame, rather than writing the same wgpu query
+        /// slot twice (which wgpu forbids between resolves).
+        pub fn write_timestamp<'w>(&self, pass: &mut TrackedRenderPass<'w>) {
+            if let Some(query_set) = &self.query_set {
+                let index = self.next_query.fetch_add(1, Ordering::Relaxed);
+                if let Some(slot) = next_profiler_slot(index, QUERY_COUNT) {
+                    pass.write_timestamp(query_set, slot);
+                }
+            }
+        }
+    }
+
+    /// Resolves this frame's timestamp queries into milliseconds, averages them across
+    /// every entity profiled, publishes the result to the shared [`Mesh2dGpuTimings`]
+    /// the main world reads from, and resets the slot counter for the next frame.
+    pub fn resolve_mesh2d_gpu_profiler(
+        profiler: Res<Mesh2dGpuProfiler>,
+        timings: Res<Mesh2dGpuTimings>,
+        render_device: Res<RenderDevice>,
+        render_queue: Res<RenderQueue>,
+    ) {
+        let (Some(query_set), Some(resolve_buffer)) = (&profiler.query_set, &profiler.resolve_buffer) else {
+            return;
+        };
+
+        let written = profiler.next_query.swap(0, Ordering::Relaxed);
+        let entities_profiled = resolved_entities_profiled(written, QUERY_COUNT, SPANS_PER_ENTITY);
+        if entities_profiled == 0 {
+            return;
+        }
+        let resolved_count = entities_profiled * SPANS_PER_ENTITY;
+
+        let mut encoder =
+            render_device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+        encoder.resolve_query_set(query_set, 0..resolved_count, resolve_buffer, 0);
+        render_queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = resolve_buffer.slice(0..(resolved_count as u64 * std::mem::size_of::<u64>() as u64));
+        slice.map_async(MapMode::Read, |_| ());
+        render_device.poll(Maintain::Wait);
+
+        let ticks: Vec<u64> = {
+            let data = slice.get_mapped_range();
+            data.chunks_exact(8)
+                .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+                .collect()
+        };
+        resolve_buffer.unmap();
+
+        let ms_between = |from: usize, to: usize| -> f32 {
+            ticks[to].saturating_sub(ticks[from]) as f32 * profiler.period_ns / 1_000_000.0
+        };
+
+        let mut view_bind_group_ms = 0.0;
+        let mut mesh_bind_group_ms = 0.0;
+        let mut draw_ms = 0.0;
+        for entity in 0..entities_profiled as usize {
+            let base = entity * SPANS_PER_ENTITY as usize;
+            view_bind_group_ms += ms_between(base, base + 1);
+            mesh_bind_group_ms += ms_between(base + 1, base + 2);
+            draw_ms += ms_between(base + 2, base + 3);
+        }
+
+        let mut inner = timings.0.lock().unwrap();
+        inner.view_bind_group_ms = view_bind_group_ms / entities_profiled as f32;
+        inner.mesh_bind_group_ms = mesh_bind_group_ms / entities_profiled as f32;
+        inner.draw_ms = draw_ms / entities_profiled as f32;
+        inner.entities_profiled = entities_profiled;
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn next_profiler_slot_allocates_until_total_then_stops() {
+            assert_eq!(next_profiler_slot(0, 4), Some(0));
+            assert_eq!(next_profiler_slot(3, 4), Some(3));
+            assert_eq!(next_profiler_slot(4, 4), None);
+            assert_eq!(next_profiler_slot(5, 4), None);
+        }
+
+        #[test]
+        fn resolved_entities_profiled_rounds_down_to_whole_blocks() {
+            let cases = [
+                // (written, total, spans_per_entity, expected)
+                (0, 16, 4, 0),
+                (4, 16, 4, 1),
+                (7, 16, 4, 1), // a partial block has nothing valid to resolve
+                (8, 16, 4, 2),
+            ];
+            for (written, total, spans_per_entity, expected) in cases {
+                assert_eq!(
+                    resolved_entities_profiled(written, total, spans_per_entity),
+                    expected,
+                    "written={} total={} spans_per_entity={}",
+                    written,
+                    total,
+                    spans_per_entity
+                );
+            }
+        }
+
+        #[test]
+        fn resolved_entities_profiled_clamps_to_total_slots() {
+            // More writes than QUERY_COUNT can happen if more than
+            // MAX_PROFILED_ENTITIES are profiled in a single frame; the excess must be
+            // clamped away rather than resolving past the query set's actual size.
+            assert_eq!(resolved_entities_profiled(1000, 16, 4), 4);
         }
     }
 }